@@ -0,0 +1,273 @@
+use rand::prelude::*;
+use snafu::prelude::*;
+
+use crate::domain::color::Color;
+use crate::domain::material::def::{Material, MaterialKind};
+use crate::domain::math::algebra::{Product, UnitVector, Vector};
+use crate::domain::math::geometry::{Rotation, Transform};
+use crate::domain::math::numeric::{DisRange, Val};
+use crate::domain::ray::sampling::{CoefSample, CoefSampling};
+use crate::domain::ray::{Ray, RayIntersection};
+use crate::domain::renderer::Context;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Glossy {
+    color: Color,
+    roughness: Val,
+}
+
+impl Glossy {
+    pub fn new(color: Color, roughness: Val) -> Result<Self, TryNewGlossyError> {
+        ensure!(
+            roughness >= Val(0.0) && roughness <= Val(1.0),
+            InvalidRoughnessSnafu
+        );
+
+        Ok(Self { color, roughness })
+    }
+
+    fn calc_next_mirror_ray(&self, ray: &Ray, intersection: &RayIntersection) -> Ray {
+        let normal = intersection.normal();
+        let dir = ray.direction();
+        Ray::new(
+            intersection.position(),
+            (dir - Val(2.0) * dir.dot(normal) * normal)
+                .normalize()
+                .expect("reflective ray's direction should not be zero vector"),
+        )
+    }
+
+    fn calc_next_glossy_ray(&self, ray: &Ray, intersection: &RayIntersection, half: Vector) -> Ray {
+        let dir = ray.direction();
+        Ray::new(
+            intersection.position(),
+            (dir - Val(2.0) * dir.dot(half) * half)
+                .normalize()
+                .expect("reflective ray's direction should not be zero vector"),
+        )
+    }
+
+    fn sample_half_vector(&self, normal: UnitVector, rng: &mut dyn RngCore) -> Vector {
+        let alpha2 = self.roughness.powi(2);
+        let u1 = Val(rng.random());
+        let u2 = Val(rng.random());
+
+        let cos_theta_h = ((Val(1.0) - u1) / (Val(1.0) + (alpha2 - Val(1.0)) * u1)).sqrt();
+        let sin_theta_h = (Val(1.0) - cos_theta_h.powi(2)).sqrt();
+        let phi = Val(2.0) * Val::PI * u2;
+        let local = Vector::new(phi.cos() * sin_theta_h, phi.sin() * sin_theta_h, cos_theta_h);
+
+        let tr = Rotation::new(UnitVector::z_direction(), normal, Val(0.0));
+        local.transform(&tr)
+    }
+
+    fn distribution(&self, cos_theta_h: Val) -> Val {
+        let alpha2 = self.roughness.powi(2);
+        let denom = Val::PI * (cos_theta_h.powi(2) * (alpha2 - Val(1.0)) + Val(1.0)).powi(2);
+        alpha2 / denom
+    }
+
+    fn smith_g1(&self, cos: Val) -> Val {
+        let alpha2 = self.roughness.powi(2);
+        let tan2 = (Val(1.0) - cos.powi(2)) / cos.powi(2);
+        Val(2.0) / (Val(1.0) + (Val(1.0) + alpha2 * tan2).sqrt())
+    }
+
+    fn smith_g(&self, cos_i: Val, cos_o: Val) -> Val {
+        self.smith_g1(cos_i) * self.smith_g1(cos_o)
+    }
+
+    fn fresnel_schlick(&self, cos: Val) -> Vector {
+        let f0 = self.color.to_vector();
+        let one = Vector::new(Val(1.0), Val(1.0), Val(1.0));
+        f0 + (one - f0) * (Val(1.0) - cos).powi(5)
+    }
+
+    fn is_mirror(&self) -> bool {
+        self.roughness == Val(0.0)
+    }
+}
+
+impl Material for Glossy {
+    fn material_kind(&self) -> MaterialKind {
+        if self.is_mirror() {
+            MaterialKind::Specular
+        } else {
+            MaterialKind::Glossy
+        }
+    }
+
+    fn bsdf(&self, ray: &Ray, intersection: &RayIntersection, ray_next: &Ray) -> Vector {
+        if self.is_mirror() {
+            unimplemented!("dirac function in mirror-like glossy BSDF can't be represented")
+        }
+
+        let normal = intersection.normal();
+        let dir = ray.direction();
+        let wo = ray_next.direction();
+
+        let Ok(half) = (wo - dir).normalize() else {
+            return Vector::new(Val(0.0), Val(0.0), Val(0.0));
+        };
+
+        let cos_i = (-dir).dot(normal);
+        let cos_o = wo.dot(normal);
+        if cos_i <= Val(0.0) || cos_o <= Val(0.0) {
+            return Vector::new(Val(0.0), Val(0.0), Val(0.0));
+        }
+
+        let cos_theta_h = half.dot(normal);
+        let d = self.distribution(cos_theta_h);
+        let g = self.smith_g(cos_i, cos_o);
+        let f = self.fresnel_schlick(wo.dot(half).abs());
+
+        f * (d * g / (Val(4.0) * cos_i * cos_o))
+    }
+
+    fn shade(
+        &self,
+        context: &mut Context<'_>,
+        ray: Ray,
+        intersection: RayIntersection,
+        depth: usize,
+    ) -> Color {
+        let sample = self.coef_sample(&ray, &intersection, *context.rng());
+        let coefficient = sample.coefficient();
+        let ray_next = sample.into_ray();
+
+        let renderer = context.renderer();
+        let radiance = renderer.trace(context, ray_next, DisRange::positive(), depth + 1);
+        coefficient * radiance
+    }
+
+    fn as_dyn(&self) -> &dyn Material {
+        self
+    }
+}
+
+impl CoefSampling for Glossy {
+    fn coef_sample(
+        &self,
+        ray: &Ray,
+        intersection: &RayIntersection,
+        rng: &mut dyn RngCore,
+    ) -> CoefSample {
+        if self.is_mirror() {
+            let direction = self.calc_next_mirror_ray(ray, intersection);
+            return CoefSample::new(direction, self.color.to_vector(), Val(1.0));
+        }
+
+        let half = self.sample_half_vector(intersection.normal(), rng);
+        let direction = self.calc_next_glossy_ray(ray, intersection, half);
+
+        let pdf = self.coef_pdf(ray, intersection, &direction);
+        if pdf <= Val(0.0) {
+            return CoefSample::new(direction, Vector::new(Val(0.0), Val(0.0), Val(0.0)), pdf);
+        }
+
+        let bsdf = self.bsdf(ray, intersection, &direction);
+        let cos_o = direction.dot(intersection.normal()).abs();
+        let coefficient = bsdf * (cos_o / pdf);
+
+        CoefSample::new(direction, coefficient, pdf)
+    }
+
+    fn coef_pdf(&self, ray: &Ray, intersection: &RayIntersection, ray_next: &Ray) -> Val {
+        if self.is_mirror() {
+            return Val(1.0);
+        }
+
+        let normal = intersection.normal();
+        let dir = ray.direction();
+        let wo = ray_next.direction();
+
+        let Ok(half) = (wo - dir).normalize() else {
+            return Val(0.0);
+        };
+
+        let wo_dot_half = wo.dot(half).abs();
+        if wo_dot_half == Val(0.0) {
+            return Val(0.0);
+        }
+
+        let cos_theta_h = half.dot(normal);
+        let d = self.distribution(cos_theta_h);
+        d * cos_theta_h.abs() / (Val(4.0) * wo_dot_half)
+    }
+}
+
+#[derive(Debug, Snafu, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TryNewGlossyError {
+    #[snafu(display("roughness is not within the range [0, 1]"))]
+    InvalidRoughness,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::math::geometry::Point;
+    use crate::domain::ray::SurfaceSide;
+
+    use super::*;
+
+    #[test]
+    fn glossy_new_fails_when_roughness_is_invalid() {
+        assert!(matches!(
+            Glossy::new(Color::WHITE, Val(-0.1)),
+            Err(TryNewGlossyError::InvalidRoughness),
+        ));
+        assert!(matches!(
+            Glossy::new(Color::WHITE, Val(1.1)),
+            Err(TryNewGlossyError::InvalidRoughness),
+        ));
+    }
+
+    #[test]
+    fn glossy_falls_back_to_mirror_reflection_when_roughness_is_zero() {
+        let sqrt3_2 = Val(3.0).sqrt() / Val(2.0);
+
+        let ray = Ray::new(
+            Point::new(sqrt3_2, Val(0.5), Val(0.0)),
+            Vector::new(-sqrt3_2, Val(-0.5), Val(0.0))
+                .normalize()
+                .unwrap(),
+        );
+
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(0.0), Val(0.0), Val(0.0)),
+            UnitVector::y_direction(),
+            SurfaceSide::Back,
+        );
+
+        let glossy = Glossy::new(Color::WHITE, Val(0.0)).unwrap();
+
+        let ray_next = glossy
+            .coef_sample(&ray, &intersection, &mut rand::rng())
+            .into_ray();
+        assert_eq!(
+            ray_next.direction(),
+            Vector::new(-sqrt3_2, Val(0.5), Val(0.0))
+                .normalize()
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn glossy_distribution_peaks_at_normal_incidence() {
+        let glossy = Glossy::new(Color::WHITE, Val(0.5)).unwrap();
+        assert!(glossy.distribution(Val(1.0)) > glossy.distribution(Val(0.5)));
+    }
+
+    #[test]
+    fn glossy_material_kind_succeeds_reporting_specular_when_mirror() {
+        let glossy = Glossy::new(Color::WHITE, Val(0.0)).unwrap();
+        assert!(matches!(glossy.material_kind(), MaterialKind::Specular));
+    }
+
+    #[test]
+    fn glossy_material_kind_succeeds_reporting_glossy_when_rough() {
+        let glossy = Glossy::new(Color::WHITE, Val(0.5)).unwrap();
+        assert!(matches!(glossy.material_kind(), MaterialKind::Glossy));
+    }
+}
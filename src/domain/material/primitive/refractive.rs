@@ -13,18 +13,33 @@ use crate::domain::renderer::Context;
 pub struct Refractive {
     color: Color,
     refractive_index: Val,
+    absorption: Color,
 }
 
 impl Refractive {
-    pub fn new(color: Color, refractive_index: Val) -> Result<Self, TryNewRefractiveError> {
+    pub fn new(
+        color: Color,
+        refractive_index: Val,
+        absorption: Color,
+    ) -> Result<Self, TryNewRefractiveError> {
         ensure!(refractive_index > Val(0.0), InvalidRefractiveIndexSnafu);
 
         Ok(Self {
             color,
             refractive_index,
+            absorption,
         })
     }
 
+    fn calc_transmittance(&self, distance: Val) -> Vector {
+        let sigma = self.absorption.to_vector();
+        Vector::new(
+            (-sigma.x() * distance).exp(),
+            (-sigma.y() * distance).exp(),
+            (-sigma.z() * distance).exp(),
+        )
+    }
+
     fn calc_next_reflective_ray(&self, ray: &Ray, intersection: &RayIntersection) -> Ray {
         let normal = intersection.normal();
         let dir = ray.direction();
@@ -113,6 +128,12 @@ impl Material for Refractive {
         let coefficient = sample.coefficient();
         let ray_next = sample.into_ray();
 
+        let coefficient = if intersection.side() == SurfaceSide::Back {
+            coefficient * self.calc_transmittance(intersection.distance())
+        } else {
+            coefficient
+        };
+
         let renderer = context.renderer();
         let radiance = renderer.trace(context, ray_next, DisRange::positive(), depth + 1);
         coefficient * radiance
@@ -158,7 +179,7 @@ mod tests {
     #[test]
     fn refractive_new_fails_when_refractive_index_is_invalid() {
         assert!(matches!(
-            Refractive::new(Color::WHITE, Val(0.0)),
+            Refractive::new(Color::WHITE, Val(0.0), Color::BLACK),
             Err(TryNewRefractiveError::InvalidRefractiveIndex),
         ));
     }
@@ -181,7 +202,7 @@ mod tests {
             SurfaceSide::Front,
         );
 
-        let refractive = Refractive::new(Color::WHITE, Val(3.0).sqrt()).unwrap();
+        let refractive = Refractive::new(Color::WHITE, Val(3.0).sqrt(), Color::BLACK).unwrap();
 
         let ray_next = refractive.calc_next_ray(&ray, &intersection, Val(1.0));
         assert_eq!(
@@ -210,7 +231,7 @@ mod tests {
             SurfaceSide::Back,
         );
 
-        let refractive = Refractive::new(Color::WHITE, Val(3.0).sqrt()).unwrap();
+        let refractive = Refractive::new(Color::WHITE, Val(3.0).sqrt(), Color::BLACK).unwrap();
 
         let ray_next = refractive.calc_next_ray(&ray, &intersection, Val(1.0));
         assert_eq!(
@@ -239,7 +260,7 @@ mod tests {
             SurfaceSide::Front,
         );
 
-        let refractive = Refractive::new(Color::WHITE, Val(3.0).sqrt()).unwrap();
+        let refractive = Refractive::new(Color::WHITE, Val(3.0).sqrt(), Color::BLACK).unwrap();
 
         let ray_next = refractive.calc_next_ray(&ray, &intersection, Val(0.0));
         assert_eq!(
@@ -249,4 +270,17 @@ mod tests {
                 .unwrap(),
         );
     }
+
+    #[test]
+    fn refractive_calc_transmittance_succeeds() {
+        let refractive =
+            Refractive::new(Color::WHITE, Val(3.0).sqrt(), Color::new(Val(1.0), Val(2.0), Val(0.0)))
+                .unwrap();
+
+        let transmittance = refractive.calc_transmittance(Val(2.0));
+        assert_eq!(
+            transmittance,
+            Vector::new((-Val(2.0)).exp(), (-Val(4.0)).exp(), Val(1.0))
+        );
+    }
 }
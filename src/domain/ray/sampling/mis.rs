@@ -0,0 +1,261 @@
+use rand::prelude::*;
+
+use crate::domain::color::Color;
+use crate::domain::material::def::{Material, MaterialKind};
+use crate::domain::math::numeric::Val;
+use crate::domain::ray::{Ray, RayIntersection};
+
+use super::LightSampling;
+
+pub fn is_delta_material(material: &dyn Material) -> bool {
+    matches!(
+        material.material_kind(),
+        MaterialKind::Specular | MaterialKind::Refractive
+    )
+}
+
+pub fn power_heuristic(p: Val, p_other: Val) -> Val {
+    let p2 = p.powi(2);
+    let p_other2 = p_other.powi(2);
+    let denom = p2 + p_other2;
+    if denom == Val(0.0) {
+        Val(0.0)
+    } else {
+        p2 / denom
+    }
+}
+
+pub fn sample_direct_lighting(
+    ray: &Ray,
+    intersection: &RayIntersection,
+    material: &dyn Material,
+    sampler: &dyn LightSampling,
+    rng: &mut dyn RngCore,
+    is_occluded: impl FnOnce(&Ray) -> bool,
+) -> Color {
+    if is_delta_material(material) {
+        return Color::BLACK;
+    }
+
+    let Some(light_sample) = sampler.light_sample(ray, intersection, material, rng) else {
+        return Color::BLACK;
+    };
+
+    if is_occluded(light_sample.ray()) {
+        return Color::BLACK;
+    }
+
+    let p_light = light_sample.pdf();
+    let p_bsdf = material.coef_pdf(ray, intersection, light_sample.ray());
+
+    let weight = if sampler.light_pdf(intersection, light_sample.ray()) == Val(0.0) {
+        Val(1.0)
+    } else {
+        power_heuristic(p_light, p_bsdf)
+    };
+
+    Color::from_vector(light_sample.coefficient() * weight)
+}
+
+pub fn weight_bsdf_sampled_hit(
+    ray: &Ray,
+    intersection: &RayIntersection,
+    material: &dyn Material,
+    sampler: &dyn LightSampling,
+    ray_next: &Ray,
+) -> Val {
+    if is_delta_material(material) {
+        return Val(1.0);
+    }
+
+    let p_bsdf = material.coef_pdf(ray, intersection, ray_next);
+    let p_light = sampler.light_pdf(intersection, ray_next);
+    power_heuristic(p_bsdf, p_light)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::material::primitive::{Glossy, Refractive, Specular};
+    use crate::domain::math::algebra::{UnitVector, Vector};
+    use crate::domain::math::geometry::Point;
+    use crate::domain::ray::SurfaceSide;
+    use crate::domain::shape::def::{ShapeId, ShapeKind};
+    use crate::domain::shape::primitive::Sphere;
+
+    use super::super::point::PointSampler;
+    use super::super::sphere::SphereSampler;
+    use super::*;
+
+    struct NullRng;
+
+    impl RngCore for NullRng {
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(0);
+        }
+    }
+
+    #[test]
+    fn power_heuristic_succeeds_weighting_equal_pdfs_evenly() {
+        assert_eq!(power_heuristic(Val(2.0), Val(2.0)), Val(0.5));
+    }
+
+    #[test]
+    fn power_heuristic_succeeds_favoring_the_larger_pdf() {
+        assert!(power_heuristic(Val(3.0), Val(1.0)) > Val(0.5));
+    }
+
+    #[test]
+    fn power_heuristic_succeeds_returning_zero_when_both_pdfs_are_zero() {
+        assert_eq!(power_heuristic(Val(0.0), Val(0.0)), Val(0.0));
+    }
+
+    #[test]
+    fn is_delta_material_succeeds_for_specular_and_refractive() {
+        let specular = Specular::new(Color::WHITE);
+        assert!(is_delta_material(&specular));
+
+        let refractive = Refractive::new(Color::WHITE, Val(1.5), Color::BLACK).unwrap();
+        assert!(is_delta_material(&refractive));
+    }
+
+    #[test]
+    fn is_delta_material_fails_for_non_delta_materials() {
+        let glossy = Glossy::new(Color::WHITE, Val(0.5)).unwrap();
+        assert!(!is_delta_material(&glossy));
+    }
+
+    #[test]
+    fn sample_direct_lighting_succeeds_giving_full_weight_for_a_delta_light() {
+        let sampler = PointSampler::new(Point::new(Val(0.0), Val(0.0), Val(1.0)), Color::WHITE);
+        let glossy = Glossy::new(Color::WHITE, Val(0.5)).unwrap();
+
+        let ray = Ray::new(
+            Point::new(Val(0.0), Val(0.0), Val(2.0)),
+            -UnitVector::z_direction(),
+        );
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(0.0), Val(0.0), Val(0.0)),
+            UnitVector::z_direction(),
+            SurfaceSide::Front,
+        );
+
+        let expected = sampler
+            .light_sample(&ray, &intersection, &glossy, &mut NullRng)
+            .unwrap();
+
+        let radiance = sample_direct_lighting(
+            &ray,
+            &intersection,
+            &glossy,
+            &sampler,
+            &mut NullRng,
+            |_| false,
+        );
+
+        assert_eq!(radiance, Color::from_vector(expected.coefficient()));
+    }
+
+    #[test]
+    fn sample_direct_lighting_succeeds_returning_black_when_occluded() {
+        let sampler = PointSampler::new(Point::new(Val(0.0), Val(0.0), Val(1.0)), Color::WHITE);
+        let glossy = Glossy::new(Color::WHITE, Val(0.5)).unwrap();
+
+        let ray = Ray::new(
+            Point::new(Val(0.0), Val(0.0), Val(2.0)),
+            -UnitVector::z_direction(),
+        );
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(0.0), Val(0.0), Val(0.0)),
+            UnitVector::z_direction(),
+            SurfaceSide::Front,
+        );
+
+        let radiance = sample_direct_lighting(
+            &ray,
+            &intersection,
+            &glossy,
+            &sampler,
+            &mut NullRng,
+            |_| true,
+        );
+
+        assert_eq!(radiance, Color::BLACK);
+    }
+
+    #[test]
+    fn weight_bsdf_sampled_hit_succeeds_matching_power_heuristic_for_area_light() {
+        let sampler = SphereSampler::new(
+            ShapeId::new(ShapeKind::Sphere, 0),
+            Sphere::new(Point::new(Val(0.0), Val(0.0), Val(0.0)), Val(2.0)).unwrap(),
+        );
+        let glossy = Glossy::new(Color::WHITE, Val(0.5)).unwrap();
+
+        let ray = Ray::new(
+            Point::new(Val(4.0), Val(0.0), Val(0.0)),
+            -UnitVector::x_direction(),
+        );
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(4.0), Val(0.0), Val(0.0)),
+            UnitVector::x_direction(),
+            SurfaceSide::Front,
+        );
+        let ray_next = Ray::new(
+            Point::new(Val(4.0), Val(0.0), Val(0.0)),
+            Vector::new(Val(-3.0), Val(1.7320508676), Val(0.0))
+                .normalize()
+                .unwrap(),
+        );
+
+        let material: &dyn Material = &glossy;
+        let p_bsdf = material.coef_pdf(&ray, &intersection, &ray_next);
+        let p_light = sampler.light_pdf(&intersection, &ray_next);
+        let expected = power_heuristic(p_bsdf, p_light);
+
+        assert_eq!(
+            weight_bsdf_sampled_hit(&ray, &intersection, &glossy, &sampler, &ray_next),
+            expected
+        );
+    }
+
+    #[test]
+    fn weight_bsdf_sampled_hit_succeeds_giving_full_weight_for_delta_materials() {
+        let sampler = SphereSampler::new(
+            ShapeId::new(ShapeKind::Sphere, 0),
+            Sphere::new(Point::new(Val(0.0), Val(0.0), Val(0.0)), Val(2.0)).unwrap(),
+        );
+        let specular = Specular::new(Color::WHITE);
+
+        let ray = Ray::new(
+            Point::new(Val(4.0), Val(0.0), Val(0.0)),
+            -UnitVector::x_direction(),
+        );
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(4.0), Val(0.0), Val(0.0)),
+            UnitVector::x_direction(),
+            SurfaceSide::Front,
+        );
+        let ray_next = Ray::new(
+            Point::new(Val(4.0), Val(0.0), Val(0.0)),
+            Vector::new(Val(-3.0), Val(1.7320508676), Val(0.0))
+                .normalize()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            weight_bsdf_sampled_hit(&ray, &intersection, &specular, &sampler, &ray_next),
+            Val(1.0)
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use rand::prelude::*;
+
+use crate::domain::material::def::Material;
+use crate::domain::math::algebra::Product;
+use crate::domain::math::numeric::{DisRange, Val};
+use crate::domain::ray::{Ray, RayIntersection};
+use crate::domain::shape::def::{Shape, ShapeId};
+use crate::domain::shape::primitive::Quad;
+
+use super::mis::is_delta_material;
+use super::{LightSample, LightSampling};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuadSampler {
+    id: ShapeId,
+    shape: Quad,
+}
+
+impl QuadSampler {
+    pub fn new(id: ShapeId, shape: Quad) -> Self {
+        Self { id, shape }
+    }
+}
+
+impl LightSampling for QuadSampler {
+    fn id(&self) -> Option<ShapeId> {
+        Some(self.id)
+    }
+
+    fn shape(&self) -> Option<&dyn Shape> {
+        Some(&self.shape)
+    }
+
+    fn light_sample(
+        &self,
+        ray: &Ray,
+        intersection: &RayIntersection,
+        material: &dyn Material,
+        rng: &mut dyn RngCore,
+    ) -> Option<LightSample> {
+        if is_delta_material(material) {
+            return None;
+        }
+
+        let u = Val(rng.random());
+        let v = Val(rng.random());
+        let point = self.shape.origin() + u * self.shape.edge1() + v * self.shape.edge2();
+
+        let to_light = point - intersection.position();
+        let dist2 = to_light.norm_squared();
+        let Ok(direction) = to_light.normalize() else {
+            return None;
+        };
+
+        let cos_light = (-direction).dot(self.shape.normal());
+        if cos_light <= Val(0.0) {
+            return None;
+        }
+
+        let ray_next = Ray::new(intersection.position(), direction);
+
+        let bsdf = material.bsdf(ray, intersection, &ray_next);
+        if bsdf.norm_squared() == Val(0.0) {
+            return None;
+        }
+
+        let pdf = dist2 / (self.shape.area() * cos_light);
+        let cos = direction.dot(intersection.normal());
+        let coefficient = bsdf * cos / pdf;
+        Some(LightSample::new(ray_next, coefficient, pdf, self.id))
+    }
+
+    fn light_pdf(&self, _intersection: &RayIntersection, ray_next: &Ray) -> Val {
+        let Some(hit) = Quad::calc_ray_intersection(
+            ray_next,
+            DisRange::positive(),
+            &self.shape.origin(),
+            &self.shape.edge1(),
+            &self.shape.edge2(),
+            &self.shape.normal(),
+        ) else {
+            return Val(0.0);
+        };
+
+        let cos_light = (-ray_next.direction()).dot(self.shape.normal());
+        if cos_light <= Val(0.0) {
+            return Val(0.0);
+        }
+
+        hit.distance().powi(2) / (self.shape.area() * cos_light)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::color::Color;
+    use crate::domain::material::primitive::Specular;
+    use crate::domain::math::algebra::{UnitVector, Vector};
+    use crate::domain::math::geometry::Point;
+    use crate::domain::shape::def::{ShapeId, ShapeKind};
+
+    use super::*;
+
+    struct NullRng;
+
+    impl RngCore for NullRng {
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(0);
+        }
+    }
+
+    #[test]
+    fn quad_sampler_light_sample_fails_instead_of_panicking_on_delta_material() {
+        let shape = Quad::new(
+            Point::new(Val(-1.0), Val(-1.0), Val(0.0)),
+            Vector::new(Val(2.0), Val(0.0), Val(0.0)),
+            Vector::new(Val(0.0), Val(2.0), Val(0.0)),
+        )
+        .unwrap();
+        let sampler = QuadSampler::new(ShapeId::new(ShapeKind::Quad, 0), shape);
+        let specular = Specular::new(Color::WHITE);
+
+        let ray = Ray::new(
+            Point::new(Val(0.0), Val(0.0), Val(2.0)),
+            -UnitVector::z_direction(),
+        );
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(0.0), Val(0.0), Val(1.0)),
+            UnitVector::z_direction(),
+            crate::domain::ray::SurfaceSide::Front,
+        );
+
+        assert!(sampler
+            .light_sample(&ray, &intersection, &specular, &mut NullRng)
+            .is_none());
+    }
+
+    #[test]
+    fn quad_sampler_light_pdf_succeeds() {
+        let shape = Quad::new(
+            Point::new(Val(-1.0), Val(-1.0), Val(0.0)),
+            Vector::new(Val(2.0), Val(0.0), Val(0.0)),
+            Vector::new(Val(0.0), Val(2.0), Val(0.0)),
+        )
+        .unwrap();
+        let sampler = QuadSampler::new(ShapeId::new(ShapeKind::Quad, 0), shape);
+
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(0.0), Val(0.0), Val(1.0)),
+            UnitVector::z_direction(),
+            crate::domain::ray::SurfaceSide::Front,
+        );
+
+        let ray_next = Ray::new(
+            Point::new(Val(0.0), Val(0.0), Val(1.0)),
+            -UnitVector::z_direction(),
+        );
+
+        assert_eq!(sampler.light_pdf(&intersection, &ray_next), Val(0.25));
+    }
+
+    #[test]
+    fn quad_sampler_light_pdf_fails_when_ray_misses_quad() {
+        let shape = Quad::new(
+            Point::new(Val(-1.0), Val(-1.0), Val(0.0)),
+            Vector::new(Val(2.0), Val(0.0), Val(0.0)),
+            Vector::new(Val(0.0), Val(2.0), Val(0.0)),
+        )
+        .unwrap();
+        let sampler = QuadSampler::new(ShapeId::new(ShapeKind::Quad, 0), shape);
+
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(5.0), Val(5.0), Val(1.0)),
+            UnitVector::z_direction(),
+            crate::domain::ray::SurfaceSide::Front,
+        );
+
+        let ray_next = Ray::new(
+            Point::new(Val(5.0), Val(5.0), Val(1.0)),
+            -UnitVector::z_direction(),
+        );
+
+        assert_eq!(sampler.light_pdf(&intersection, &ray_next), Val(0.0));
+    }
+}
@@ -0,0 +1,162 @@
+use rand::prelude::*;
+
+use crate::domain::color::Color;
+use crate::domain::material::def::Material;
+use crate::domain::math::algebra::{Product, UnitVector};
+use crate::domain::math::geometry::Point;
+use crate::domain::math::numeric::Val;
+use crate::domain::ray::{Ray, RayIntersection};
+use crate::domain::shape::def::{Shape, ShapeId};
+
+use super::mis::is_delta_material;
+use super::{LightSample, LightSampling};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpotSampler {
+    position: Point,
+    intensity: Color,
+    direction: UnitVector,
+    cos_inner: Val,
+    cos_outer: Val,
+}
+
+impl SpotSampler {
+    pub fn new(
+        position: Point,
+        intensity: Color,
+        direction: UnitVector,
+        cos_inner: Val,
+        cos_outer: Val,
+    ) -> Self {
+        Self {
+            position,
+            intensity,
+            direction,
+            cos_inner,
+            cos_outer,
+        }
+    }
+
+    fn calc_cutoff(&self, direction_to_light: UnitVector) -> Val {
+        let cos_angle = (-direction_to_light).dot(self.direction);
+        smoothstep(self.cos_outer, self.cos_inner, cos_angle)
+    }
+}
+
+fn smoothstep(edge0: Val, edge1: Val, x: Val) -> Val {
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(Val(0.0), Val(1.0));
+    t * t * (Val(3.0) - Val(2.0) * t)
+}
+
+impl LightSampling for SpotSampler {
+    fn id(&self) -> Option<ShapeId> {
+        None
+    }
+
+    fn shape(&self) -> Option<&dyn Shape> {
+        None
+    }
+
+    fn light_sample(
+        &self,
+        ray: &Ray,
+        intersection: &RayIntersection,
+        material: &dyn Material,
+        _rng: &mut dyn RngCore,
+    ) -> Option<LightSample> {
+        if is_delta_material(material) {
+            return None;
+        }
+
+        let to_light = self.position - intersection.position();
+        let dist2 = to_light.norm_squared();
+        let Ok(direction) = to_light.normalize() else {
+            return None;
+        };
+
+        let cutoff = self.calc_cutoff(direction);
+        if cutoff <= Val(0.0) {
+            return None;
+        }
+
+        let ray_next = Ray::new(intersection.position(), direction);
+
+        let bsdf = material.bsdf(ray, intersection, &ray_next);
+        if bsdf.norm_squared() == Val(0.0) {
+            return None;
+        }
+
+        let cos = direction.dot(intersection.normal());
+        let falloff = cos * cutoff / dist2;
+        let coefficient = bsdf * self.intensity.to_vector() * falloff;
+
+        Some(LightSample::new(ray_next, coefficient, Val(1.0), None))
+    }
+
+    fn light_pdf(&self, _intersection: &RayIntersection, _ray_next: &Ray) -> Val {
+        Val(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::material::primitive::Specular;
+    use crate::domain::math::geometry::Point;
+    use crate::domain::ray::SurfaceSide;
+
+    use super::*;
+
+    struct NullRng;
+
+    impl RngCore for NullRng {
+        fn next_u32(&mut self) -> u32 {
+            0
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            0
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(0);
+        }
+    }
+
+    #[test]
+    fn smoothstep_succeeds_clamping_outside_the_edges() {
+        assert_eq!(smoothstep(Val(0.0), Val(1.0), Val(-1.0)), Val(0.0));
+        assert_eq!(smoothstep(Val(0.0), Val(1.0), Val(2.0)), Val(1.0));
+    }
+
+    #[test]
+    fn smoothstep_succeeds_at_the_midpoint() {
+        assert_eq!(smoothstep(Val(0.0), Val(1.0), Val(0.5)), Val(0.5));
+    }
+
+    #[test]
+    fn spot_sampler_light_sample_fails_instead_of_panicking_on_delta_material() {
+        let sampler = SpotSampler::new(
+            Point::new(Val(0.0), Val(0.0), Val(1.0)),
+            Color::WHITE,
+            -UnitVector::z_direction(),
+            Val(0.9),
+            Val(0.8),
+        );
+        let specular = Specular::new(Color::WHITE);
+
+        let ray = Ray::new(
+            Point::new(Val(0.0), Val(0.0), Val(2.0)),
+            -UnitVector::z_direction(),
+        );
+        let intersection = RayIntersection::new(
+            Val(1.0),
+            Point::new(Val(0.0), Val(0.0), Val(0.0)),
+            UnitVector::z_direction(),
+            SurfaceSide::Front,
+        );
+
+        assert!(sampler
+            .light_sample(&ray, &intersection, &specular, &mut NullRng)
+            .is_none());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::domain::color::Color;
+use crate::domain::math::numeric::Val;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fog {
+    color: Color,
+    kind: FogKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FogKind {
+    Exponential { density: Val },
+    Linear { near: Val, far: Val },
+}
+
+impl Fog {
+    pub fn exponential(color: Color, density: Val) -> Self {
+        Self {
+            color,
+            kind: FogKind::Exponential { density },
+        }
+    }
+
+    pub fn linear(color: Color, near: Val, far: Val) -> Self {
+        Self {
+            color,
+            kind: FogKind::Linear { near, far },
+        }
+    }
+
+    pub fn apply(&self, radiance: Color, distance: Val) -> Color {
+        let alpha = match self.kind {
+            FogKind::Exponential { density } => (-density * distance).exp(),
+            FogKind::Linear { near, far } => {
+                ((far - distance) / (far - near)).clamp(Val(0.0), Val(1.0))
+            }
+        };
+
+        radiance * alpha + self.color * (Val(1.0) - alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fog_apply_succeeds_with_exponential_fog_at_zero_distance() {
+        let fog = Fog::exponential(Color::BLACK, Val(0.5));
+        assert_eq!(fog.apply(Color::WHITE, Val(0.0)), Color::WHITE);
+    }
+
+    #[test]
+    fn fog_apply_succeeds_with_linear_fog_beyond_the_far_distance() {
+        let fog = Fog::linear(Color::BLACK, Val(1.0), Val(2.0));
+        assert_eq!(fog.apply(Color::WHITE, Val(3.0)), Color::BLACK);
+    }
+
+    #[test]
+    fn fog_apply_succeeds_with_linear_fog_before_the_near_distance() {
+        let fog = Fog::linear(Color::BLACK, Val(1.0), Val(2.0));
+        assert_eq!(fog.apply(Color::WHITE, Val(0.0)), Color::WHITE);
+    }
+}
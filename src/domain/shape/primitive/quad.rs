@@ -0,0 +1,207 @@
+use std::ops::RangeBounds;
+
+use snafu::prelude::*;
+
+use crate::domain::math::algebra::{Product, UnitVector, Vector};
+use crate::domain::math::geometry::Point;
+use crate::domain::math::numeric::{DisRange, Val};
+use crate::domain::ray::sampling::{LightSampling, QuadSampler};
+use crate::domain::ray::{Ray, RayIntersection, SurfaceSide};
+use crate::domain::shape::def::{BoundingBox, Shape, ShapeId, ShapeKind};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quad {
+    origin: Point,
+    edge1: Vector,
+    edge2: Vector,
+    normal: UnitVector,
+    area: Val,
+}
+
+impl Quad {
+    pub fn new(origin: Point, edge1: Vector, edge2: Vector) -> Result<Self, TryNewQuadError> {
+        let cross = edge1.cross(edge2);
+        let area = cross.norm();
+        ensure!(area > Val(0.0), DegenerateQuadSnafu);
+
+        let normal = cross
+            .normalize()
+            .expect("cross product is not zero since area is positive");
+
+        Ok(Self {
+            origin,
+            edge1,
+            edge2,
+            normal,
+            area,
+        })
+    }
+
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    pub fn edge1(&self) -> Vector {
+        self.edge1
+    }
+
+    pub fn edge2(&self) -> Vector {
+        self.edge2
+    }
+
+    pub fn normal(&self) -> UnitVector {
+        self.normal
+    }
+
+    pub fn area(&self) -> Val {
+        self.area
+    }
+
+    pub fn calc_ray_intersection(
+        ray: &Ray,
+        range: DisRange,
+        origin: &Point,
+        edge1: &Vector,
+        edge2: &Vector,
+        normal: &UnitVector,
+    ) -> Option<RayIntersection> {
+        let den = ray.direction().dot(*normal);
+        if den == Val(0.0) {
+            return None;
+        }
+
+        let num = (*origin - ray.start()).dot(*normal);
+        let distance = num / den;
+        if distance <= Val(0.0) || !range.contains(&distance) {
+            return None;
+        }
+
+        let position = ray.at(distance);
+        let v = position - *origin;
+
+        let e1e1 = edge1.dot(*edge1);
+        let e1e2 = edge1.dot(*edge2);
+        let e2e2 = edge2.dot(*edge2);
+        let ve1 = v.dot(*edge1);
+        let ve2 = v.dot(*edge2);
+
+        let denom = e1e1 * e2e2 - e1e2 * e1e2;
+        if denom == Val(0.0) {
+            return None;
+        }
+
+        let u = (ve1 * e2e2 - ve2 * e1e2) / denom;
+        let w = (ve2 * e1e1 - ve1 * e1e2) / denom;
+        if !(Val(0.0)..=Val(1.0)).contains(&u) || !(Val(0.0)..=Val(1.0)).contains(&w) {
+            return None;
+        }
+
+        let (normal, side) = if den < Val(0.0) {
+            (*normal, SurfaceSide::Front)
+        } else {
+            (-(*normal), SurfaceSide::Back)
+        };
+        Some(RayIntersection::new(distance, position, normal, side))
+    }
+
+    fn bounding_corners(&self) -> [Point; 4] {
+        [
+            self.origin,
+            self.origin + self.edge1,
+            self.origin + self.edge2,
+            self.origin + self.edge1 + self.edge2,
+        ]
+    }
+}
+
+impl Shape for Quad {
+    fn shape_kind(&self) -> ShapeKind {
+        ShapeKind::Quad
+    }
+
+    fn hit(&self, ray: &Ray, range: DisRange) -> Option<RayIntersection> {
+        Self::calc_ray_intersection(ray, range, &self.origin, &self.edge1, &self.edge2, &self.normal)
+    }
+
+    fn bounding_box(&self) -> Option<BoundingBox> {
+        let corners = self.bounding_corners();
+        let min = Point::new(
+            corners.iter().map(|p| p.x()).fold(corners[0].x(), Val::min),
+            corners.iter().map(|p| p.y()).fold(corners[0].y(), Val::min),
+            corners.iter().map(|p| p.z()).fold(corners[0].z(), Val::min),
+        );
+        let max = Point::new(
+            corners.iter().map(|p| p.x()).fold(corners[0].x(), Val::max),
+            corners.iter().map(|p| p.y()).fold(corners[0].y(), Val::max),
+            corners.iter().map(|p| p.z()).fold(corners[0].z(), Val::max),
+        );
+        Some(BoundingBox::new(min, max))
+    }
+
+    fn get_sampler(&self, shape_id: ShapeId) -> Option<Box<dyn LightSampling>> {
+        Some(Box::new(QuadSampler::new(shape_id, self.clone())))
+    }
+}
+
+#[derive(Debug, Snafu, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TryNewQuadError {
+    #[snafu(display("edges are parallel so the quad has zero area"))]
+    DegenerateQuad,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quad_new_fails_when_edges_are_parallel() {
+        assert!(matches!(
+            Quad::new(
+                Point::new(Val(0.0), Val(0.0), Val(0.0)),
+                Vector::new(Val(1.0), Val(0.0), Val(0.0)),
+                Vector::new(Val(2.0), Val(0.0), Val(0.0)),
+            ),
+            Err(TryNewQuadError::DegenerateQuad),
+        ));
+    }
+
+    #[test]
+    fn quad_hit_succeeds() {
+        let quad = Quad::new(
+            Point::new(Val(-1.0), Val(-1.0), Val(0.0)),
+            Vector::new(Val(2.0), Val(0.0), Val(0.0)),
+            Vector::new(Val(0.0), Val(2.0), Val(0.0)),
+        )
+        .unwrap();
+
+        let ray = Ray::new(
+            Point::new(Val(0.0), Val(0.0), Val(1.0)),
+            -UnitVector::z_direction(),
+        );
+
+        let intersection = quad.hit(&ray, DisRange::positive()).unwrap();
+        assert_eq!(intersection.distance(), Val(1.0));
+        assert_eq!(
+            intersection.position(),
+            Point::new(Val(0.0), Val(0.0), Val(0.0))
+        );
+    }
+
+    #[test]
+    fn quad_hit_fails_when_outside_bounds() {
+        let quad = Quad::new(
+            Point::new(Val(-1.0), Val(-1.0), Val(0.0)),
+            Vector::new(Val(2.0), Val(0.0), Val(0.0)),
+            Vector::new(Val(0.0), Val(2.0), Val(0.0)),
+        )
+        .unwrap();
+
+        let ray = Ray::new(
+            Point::new(Val(5.0), Val(5.0), Val(1.0)),
+            -UnitVector::z_direction(),
+        );
+
+        assert!(quad.hit(&ray, DisRange::positive()).is_none());
+    }
+}